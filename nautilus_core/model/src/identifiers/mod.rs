@@ -0,0 +1,180 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2023 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+pub mod trader_id;
+
+use ustr::Ustr;
+
+/// Common behaviour shared by every Nautilus identifier type.
+///
+/// All identifiers are newtypes over an interned [`Ustr`] value, so generic
+/// code can accept `impl Identifier` rather than enumerating every concrete
+/// identifier type.
+pub trait Identifier {
+    /// Returns the interned string value of the identifier.
+    fn value(&self) -> Ustr;
+
+    /// Returns the pre-computed hash of the identifier's interned value.
+    fn precomputed_hash(&self) -> u64 {
+        self.value().precomputed_hash()
+    }
+}
+
+/// Generates a Nautilus identifier newtype backed by an interned [`Ustr`] value.
+///
+/// Produces the struct along with its [`Identifier`], `Default`, `Debug`,
+/// `Display`, `FromStr`, `Serialize` and `Deserialize` implementations
+/// (`Hash`, `Eq` and `Ord` are derived), so every identifier shares the same
+/// representation, hashing and (de)serialization characteristics.
+/// Deserialization routes through `new`, so invalid values are rejected and
+/// repeated reads of the same identifier re-use the interned `Ustr`.
+/// A uniform `<snake_case>_new` / `<snake_case>_new_checked` / `<snake_case>_hash`
+/// C API is generated alongside it, with `_new_checked` writing through an
+/// out-pointer and returning a status code instead of panicking across the
+/// FFI boundary.
+///
+/// `$correctness` receives the candidate string as `$s` and should validate it
+/// with `?`, encoding the per-type correctness rule (e.g. the mandatory `-`
+/// separator for [`TraderId`](crate::identifiers::trader_id::TraderId)).
+#[macro_export]
+macro_rules! define_identifier {
+    ($ty:ident, $default:expr, |$s:ident| $correctness:block) => {
+        #[repr(C)]
+        #[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+        #[::pyo3::prelude::pyclass]
+        pub struct $ty {
+            pub value: ::ustr::Ustr,
+        }
+
+        impl $ty {
+            pub fn new($s: &str) -> ::anyhow::Result<Self> {
+                $correctness
+
+                Ok(Self {
+                    value: ::ustr::Ustr::from($s),
+                })
+            }
+        }
+
+        impl $crate::identifiers::Identifier for $ty {
+            fn value(&self) -> ::ustr::Ustr {
+                self.value
+            }
+        }
+
+        impl ::std::fmt::Debug for $ty {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                write!(f, "{:?}", self.value)
+            }
+        }
+
+        impl ::std::fmt::Display for $ty {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                write!(f, "{}", self.value)
+            }
+        }
+
+        impl Default for $ty {
+            fn default() -> Self {
+                Self {
+                    value: ::ustr::Ustr::from($default),
+                }
+            }
+        }
+
+        impl ::std::str::FromStr for $ty {
+            type Err = ::anyhow::Error;
+
+            fn from_str(s: &str) -> ::anyhow::Result<Self> {
+                Self::new(s)
+            }
+        }
+
+        impl ::serde::Serialize for $ty {
+            fn serialize<__S>(&self, serializer: __S) -> ::std::result::Result<__S::Ok, __S::Error>
+            where
+                __S: ::serde::Serializer,
+            {
+                serializer.serialize_str(&self.value)
+            }
+        }
+
+        impl<'de> ::serde::Deserialize<'de> for $ty {
+            fn deserialize<__D>(deserializer: __D) -> ::std::result::Result<Self, __D::Error>
+            where
+                __D: ::serde::Deserializer<'de>,
+            {
+                // Deserializes to `String` rather than the interned `Ustr` directly, then
+                // routes through `new`/`Ustr::from` so the value is validated and re-uses
+                // the existing interned string on repeated reads.
+                let s = ::std::string::String::deserialize(deserializer)?;
+                Self::new(&s).map_err(::serde::de::Error::custom)
+            }
+        }
+
+        ::paste::paste! {
+            #[doc = concat!("Returns a Nautilus `", stringify!($ty), "` from a C string pointer.")]
+            ///
+            /// # Safety
+            ///
+            /// - Assumes `ptr` is a valid C string pointer.
+            #[no_mangle]
+            pub unsafe extern "C" fn [<$ty:snake _new>](ptr: *const ::std::ffi::c_char) -> $ty {
+                assert!(!ptr.is_null(), "`ptr` was NULL");
+                $ty::new(::std::ffi::CStr::from_ptr(ptr).to_str().expect("CStr::from_ptr failed")).unwrap()
+            }
+
+            #[doc = concat!(
+                "Writes a `", stringify!($ty), "` constructed from a C string pointer through `out`, ",
+                "without panicking."
+            )]
+            ///
+            /// Returns `0` on success, or `-1` if `ptr` is null, not valid UTF-8, or fails
+            /// correctness checks, in which case `out` is left untouched.
+            ///
+            /// # Safety
+            ///
+            /// - Assumes `ptr` is a valid C string pointer (or null).
+            /// - Assumes `out` is a valid pointer to a correctly sized allocation.
+            #[no_mangle]
+            pub unsafe extern "C" fn [<$ty:snake _new_checked>](
+                ptr: *const ::std::ffi::c_char,
+                out: *mut $ty,
+            ) -> i8 {
+                if ptr.is_null() || out.is_null() {
+                    return -1;
+                }
+
+                let s = match ::std::ffi::CStr::from_ptr(ptr).to_str() {
+                    Ok(s) => s,
+                    Err(_) => return -1,
+                };
+
+                match $ty::new(s) {
+                    Ok(id) => {
+                        ::std::ptr::write(out, id);
+                        0
+                    }
+                    Err(_) => -1,
+                }
+            }
+
+            #[no_mangle]
+            pub extern "C" fn [<$ty:snake _hash>](id: &$ty) -> u64 {
+                id.value.precomputed_hash()
+            }
+        }
+    };
+}
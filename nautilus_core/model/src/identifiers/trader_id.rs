@@ -13,71 +13,44 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::{
-    ffi::{c_char, CStr},
-    fmt::{Debug, Display, Formatter},
-};
-
-use anyhow::Result;
 use nautilus_core::correctness;
-use pyo3::prelude::*;
-use ustr::Ustr;
-
-#[repr(C)]
-#[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
-#[pyclass]
-pub struct TraderId {
-    pub value: Ustr,
-}
 
-impl TraderId {
-    pub fn new(s: &str) -> Result<Self> {
-        correctness::valid_string(s, "`TraderId` value")?;
-        correctness::string_contains(s, "-", "`TraderId` value")?;
+use crate::define_identifier;
 
-        Ok(Self {
-            value: Ustr::from(s),
-        })
-    }
-}
+define_identifier!(TraderId, "TRADER-000", |s| {
+    correctness::valid_string(s, "`TraderId` value")?;
+    correctness::string_contains(s, "-", "`TraderId` value")?;
+});
 
-impl Default for TraderId {
-    fn default() -> Self {
-        Self {
-            value: Ustr::from("TRADER-000"),
-        }
-    }
-}
+impl TraderId {
+    /// Creates a new `TraderId` by joining `name` and `tag` with a `-` separator.
+    ///
+    /// Returns an error if `name` or `tag` contains `-`, so the only `-` in the
+    /// joined value is the separator that `get_name`/`get_tag` split on.
+    pub fn from_parts(name: &str, tag: &str) -> anyhow::Result<Self> {
+        anyhow::ensure!(!name.contains('-'), "`name` cannot contain '-', was '{name}'");
+        anyhow::ensure!(!tag.contains('-'), "`tag` cannot contain '-', was '{tag}'");
 
-impl Debug for TraderId {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:?}", self.value)
+        Self::new(&format!("{name}-{tag}"))
     }
-}
 
-impl Display for TraderId {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.value)
+    /// Returns the `NAME` component, i.e. the substring before the first `-`.
+    ///
+    /// Returns `None` if `value` has no `-` separator. This should not happen
+    /// for a `TraderId` built through `new`/`from_parts`, but `value` is a
+    /// `pub` field so it cannot be relied on unconditionally.
+    pub fn get_name(&self) -> Option<&str> {
+        self.value.split_once('-').map(|(name, _)| name)
     }
-}
-
-////////////////////////////////////////////////////////////////////////////////
-// C API
-////////////////////////////////////////////////////////////////////////////////
-/// Returns a Nautilus identifier from a C string pointer.
-///
-/// # Safety
-///
-/// - Assumes `ptr` is a valid C string pointer.
-#[no_mangle]
-pub unsafe extern "C" fn trader_id_new(ptr: *const c_char) -> TraderId {
-    assert!(!ptr.is_null(), "`ptr` was NULL");
-    TraderId::new(CStr::from_ptr(ptr).to_str().expect("CStr::from_ptr failed")).unwrap()
-}
 
-#[no_mangle]
-pub extern "C" fn trader_id_hash(id: &TraderId) -> u64 {
-    id.value.precomputed_hash()
+    /// Returns the `TAG` component, i.e. the substring after the first `-`.
+    ///
+    /// Returns `None` if `value` has no `-` separator. This should not happen
+    /// for a `TraderId` built through `new`/`from_parts`, but `value` is a
+    /// `pub` field so it cannot be relied on unconditionally.
+    pub fn get_tag(&self) -> Option<&str> {
+        self.value.split_once('-').map(|(_, tag)| tag)
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -85,7 +58,9 @@ pub extern "C" fn trader_id_hash(id: &TraderId) -> u64 {
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
-    use super::TraderId;
+    use std::{ffi::CString, str::FromStr};
+
+    use super::{trader_id_new_checked, TraderId};
 
     #[test]
     fn test_string_reprs() {
@@ -93,4 +68,75 @@ mod tests {
         assert_eq!(trader_id.to_string(), "TRADER-001");
         assert_eq!(format!("{trader_id}"), "TRADER-001");
     }
+
+    #[test]
+    fn test_trader_id_new_checked_valid() {
+        let cstring = CString::new("TRADER-001").unwrap();
+        let mut out = TraderId::default();
+        let rc = unsafe { trader_id_new_checked(cstring.as_ptr(), &mut out) };
+        assert_eq!(rc, 0);
+        assert_eq!(out.to_string(), "TRADER-001");
+    }
+
+    #[test]
+    fn test_trader_id_new_checked_invalid() {
+        let cstring = CString::new("TRADER").unwrap(); // Missing '-' separator
+        let mut out = TraderId::default();
+        let rc = unsafe { trader_id_new_checked(cstring.as_ptr(), &mut out) };
+        assert_eq!(rc, -1);
+    }
+
+    #[test]
+    fn test_get_name_and_tag() {
+        let trader_id = TraderId::new("TRADER-001").unwrap();
+        assert_eq!(trader_id.get_name(), Some("TRADER"));
+        assert_eq!(trader_id.get_tag(), Some("001"));
+    }
+
+    #[test]
+    fn test_get_name_and_tag_on_malformed_value() {
+        // `value` is `pub`, so a caller can bypass `new`'s correctness checks.
+        let trader_id = TraderId {
+            value: ustr::Ustr::from("NODASH"),
+        };
+        assert_eq!(trader_id.get_name(), None);
+        assert_eq!(trader_id.get_tag(), None);
+    }
+
+    #[test]
+    fn test_from_parts() {
+        let trader_id = TraderId::from_parts("TRADER", "001").unwrap();
+        assert_eq!(trader_id.to_string(), "TRADER-001");
+        assert_eq!(trader_id.get_name(), Some("TRADER"));
+        assert_eq!(trader_id.get_tag(), Some("001"));
+    }
+
+    #[test]
+    fn test_from_parts_rejects_embedded_separator() {
+        assert!(TraderId::from_parts("AB-CD", "EF").is_err());
+        assert!(TraderId::from_parts("AB", "CD-EF").is_err());
+    }
+
+    #[test]
+    fn test_from_str() {
+        let trader_id = TraderId::from_str("TRADER-001").unwrap();
+        assert_eq!(trader_id.to_string(), "TRADER-001");
+        assert!(TraderId::from_str("TRADER").is_err());
+    }
+
+    #[test]
+    fn test_serde_json_round_trip() {
+        let trader_id = TraderId::new("TRADER-001").unwrap();
+        let json = serde_json::to_string(&trader_id).unwrap();
+        assert_eq!(json, "\"TRADER-001\"");
+
+        let deserialized: TraderId = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, trader_id);
+    }
+
+    #[test]
+    fn test_serde_json_rejects_missing_separator() {
+        let result: Result<TraderId, _> = serde_json::from_str("\"TRADER\"");
+        assert!(result.is_err());
+    }
 }